@@ -0,0 +1,3 @@
+//! EVM/revm helpers shared across reth's node, RPC and example binaries.
+
+pub mod tracing;
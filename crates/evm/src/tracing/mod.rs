@@ -0,0 +1,6 @@
+//! Inspector implementations shared between tracing RPC handlers (`trace_`/`debug_`) and
+//! anything else that wants to inspect an EVM execution.
+
+mod struct_log;
+
+pub use struct_log::{CallFrame, StructLog, StructLogConfig, StructLogInspector};
@@ -0,0 +1,336 @@
+//! A reusable, geth-compatible struct-log inspector.
+//!
+//! This replaces the old ad-hoc `DummyInspector` that used to live in the `custom-inspector`
+//! example and only recorded `pc: opcode` strings. It captures the same information
+//! `debug_traceTransaction` does (program counter, opcode, gas, computed gas cost, call depth,
+//! stack/memory/storage) and additionally stitches together a nested call tree from the
+//! call/create enter and exit hooks, so the example and any `trace_`/`debug_` RPC handler can
+//! share this one implementation instead of ad-hoc logging.
+//!
+//! It is also EIP-7702-aware: when execution enters an account whose code is a delegation
+//! designator (`0xef0100 ++ address`), the call frame is annotated with the delegation target
+//! and, if the block's applied authorization list was supplied, with whether that delegation was
+//! (re-)authorized this block.
+
+use alloy_eips::eip7702::SignedAuthorization;
+use alloy_primitives::{Address, Bytes, B256, U256};
+use reth_codecs::alloy::authorization_list::recover_authority;
+use revm::{
+    bytecode::opcode::OpCode,
+    context_interface::ContextTr,
+    inspector::Inspector,
+    interpreter::{
+        interpreter::EthInterpreter, interpreter_types::Jumps, CallInputs, CallOutcome,
+        CallScheme, CreateInputs, CreateOutcome, Interpreter,
+    },
+};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The 3-byte prefix marking an account's code as an EIP-7702 delegation designator.
+const DELEGATION_DESIGNATOR_PREFIX: [u8; 3] = [0xef, 0x01, 0x00];
+
+/// If `code` is an EIP-7702 delegation designator (`0xef0100 ++ address`), returns the address it
+/// delegates to.
+fn parse_delegation(code: &[u8]) -> Option<Address> {
+    if code.len() == 23 && code[..3] == DELEGATION_DESIGNATOR_PREFIX {
+        Some(Address::from_slice(&code[3..]))
+    } else {
+        None
+    }
+}
+
+/// The 4-byte selector of the Solidity-generated `Error(string)` revert payload.
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// The 4-byte selector of the Solidity-generated `Panic(uint256)` revert payload.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Best-effort ABI-decodes a revert reason out of a call's returned `output`, recognizing the
+/// `Error(string)` and `Panic(uint256)` shapes Solidity emits. Falls back to `None` (rather than
+/// the raw `InstructionResult`) when the output isn't one of those shapes, so callers can fall
+/// back to their own formatting.
+fn decode_revert_reason(output: &[u8]) -> Option<String> {
+    if output.len() < 4 {
+        return None;
+    }
+    let (selector, data) = output.split_at(4);
+    match selector {
+        s if s == ERROR_STRING_SELECTOR => {
+            let len: usize = U256::from_be_slice(data.get(32..64)?).try_into().ok()?;
+            let bytes = data.get(64..64 + len)?;
+            String::from_utf8(bytes.to_vec()).ok()
+        }
+        s if s == PANIC_SELECTOR => {
+            let code = U256::from_be_slice(data.get(0..32)?);
+            Some(format!("panic: {code}"))
+        }
+        _ => None,
+    }
+}
+
+/// Controls which parts of a [`StructLog`] are populated.
+///
+/// Capturing the stack, memory and storage on every step is expensive, so callers that only
+/// care about the opcode trace (or about throughput) can turn them off individually.
+#[derive(Debug, Clone, Copy)]
+pub struct StructLogConfig {
+    /// Capture the top of the stack for each step.
+    pub stack: bool,
+    /// Capture the full memory for each step.
+    pub memory: bool,
+    /// Capture the storage slots touched by each step.
+    pub storage: bool,
+    /// Number of stack words to capture from the top, when `stack` is enabled.
+    pub stack_depth: usize,
+}
+
+impl Default for StructLogConfig {
+    fn default() -> Self {
+        Self { stack: true, memory: false, storage: true, stack_depth: 10 }
+    }
+}
+
+/// A single entry of the `structLogs` array produced by `debug_traceTransaction`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StructLog {
+    pub pc: u64,
+    pub op: String,
+    pub gas: u64,
+    #[serde(rename = "gasCost")]
+    pub gas_cost: u64,
+    pub depth: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack: Option<Vec<U256>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Vec<B256>>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub storage: BTreeMap<B256, B256>,
+    /// The delegation target of `interp.input.target_address()`, if execution is currently
+    /// inside an EIP-7702 delegated account.
+    #[serde(rename = "delegateTo", skip_serializing_if = "Option::is_none")]
+    pub delegate_to: Option<Address>,
+}
+
+/// One node of the nested call tree, mirroring geth's `callTracer` output.
+#[derive(Debug, Clone, Serialize)]
+pub struct CallFrame {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub input: Bytes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Bytes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<U256>,
+    #[serde(rename = "revertReason", skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
+    /// The implementation address `to`'s code delegates to, if `to`'s code is an EIP-7702
+    /// delegation designator.
+    #[serde(rename = "delegationTarget", skip_serializing_if = "Option::is_none")]
+    pub delegation_target: Option<Address>,
+    /// Whether `to` is an EIP-7702 authority whose delegation designator was (re-)authorized by
+    /// the block's applied authorization list, as opposed to one already in place from an
+    /// earlier block. `to` *is* the authority here (EIP-7702 delegation designators live on the
+    /// authority's own account), so this flags provenance rather than duplicating `to`.
+    #[serde(rename = "reauthorizedThisBlock")]
+    pub reauthorized_this_block: bool,
+    pub calls: Vec<CallFrame>,
+}
+
+/// Structured struct-log tracer that mirrors the `structLogs` shape of `debug_traceTransaction`
+/// while also building a nested call tree from the call/create enter and exit callbacks.
+#[derive(Debug, Clone, Default)]
+pub struct StructLogInspector {
+    config: StructLogConfig,
+    /// Flat list of per-step struct logs, in execution order.
+    pub struct_logs: Vec<StructLog>,
+    /// Stack of call frames currently open; the root frame (if any) sits at index 0.
+    call_stack: Vec<CallFrame>,
+    /// Completed top-level call frame, once the outermost call/create has returned.
+    pub root_call: Option<CallFrame>,
+    /// Addresses that delegated their code away via the block's applied authorization list. An
+    /// EIP-7702 authority's own address is exactly where the delegation designator lives, so
+    /// membership in this set *is* the recovered authority.
+    authorized_this_block: BTreeSet<Address>,
+    /// The storage slot a `SLOAD`/`SSTORE` in the just-recorded step is about to touch, stashed
+    /// in `step` (before the opcode runs) and resolved in `step_end` once it has.
+    pending_storage_key: Option<(Address, U256)>,
+}
+
+impl StructLogInspector {
+    /// Creates a new inspector with the default capture configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new inspector with a custom capture configuration.
+    pub fn with_config(config: StructLogConfig) -> Self {
+        Self { config, ..Default::default() }
+    }
+
+    /// Supplies the block's applied EIP-7702 authorization list so that call frames entering a
+    /// delegated account can be annotated with the authority that set up the delegation.
+    pub fn with_authorization_list<'a>(
+        mut self,
+        authorizations: impl IntoIterator<Item = &'a SignedAuthorization>,
+    ) -> Self {
+        for authorization in authorizations {
+            if let Ok(authority) = recover_authority(authorization) {
+                self.authorized_this_block.insert(authority);
+            }
+        }
+        self
+    }
+
+    fn push_frame(
+        &mut self,
+        kind: &'static str,
+        from: Address,
+        to: Option<Address>,
+        input: Bytes,
+        value: Option<U256>,
+        delegation_target: Option<Address>,
+        reauthorized_this_block: bool,
+    ) {
+        self.call_stack.push(CallFrame {
+            kind,
+            from,
+            to,
+            input,
+            output: None,
+            value,
+            revert_reason: None,
+            delegation_target,
+            reauthorized_this_block,
+            calls: Vec::new(),
+        });
+    }
+
+    fn pop_frame(&mut self, output: Option<Bytes>, revert_reason: Option<String>) {
+        let Some(mut frame) = self.call_stack.pop() else { return };
+        frame.output = output;
+        frame.revert_reason = revert_reason;
+
+        match self.call_stack.last_mut() {
+            Some(parent) => parent.calls.push(frame),
+            None => self.root_call = Some(frame),
+        }
+    }
+}
+
+impl<CTX> Inspector<CTX, EthInterpreter> for StructLogInspector
+where
+    CTX: ContextTr,
+{
+    fn step(&mut self, interp: &mut Interpreter<EthInterpreter>, _context: &mut CTX) {
+        let Some(opcode) = OpCode::new(interp.bytecode.opcode()) else { return };
+
+        let gas = interp.control.gas().remaining();
+
+        let stack = self.config.stack.then(|| {
+            let data = interp.stack.data();
+            let start = data.len().saturating_sub(self.config.stack_depth);
+            data[start..].to_vec()
+        });
+
+        let memory =
+            self.config.memory.then(|| interp.memory.context_memory().chunks(32).map(B256::from_slice).collect());
+
+        // Only peek the slot the opcode is about to touch here; looking it up through the
+        // journal (and thus marking it warm) happens in `step_end`, once the real opcode has
+        // already done so itself, so tracing never perturbs the transaction's own gas usage.
+        self.pending_storage_key = (self.config.storage && matches!(opcode.as_str(), "SLOAD" | "SSTORE"))
+            .then(|| interp.stack.data().last().copied())
+            .flatten()
+            .map(|key| (interp.input.target_address(), key));
+
+        let delegate_to = self.call_stack.last().and_then(|frame| frame.delegation_target);
+
+        self.struct_logs.push(StructLog {
+            pc: interp.bytecode.pc() as u64,
+            op: opcode.to_string(),
+            gas,
+            // patched in `step_end` once the opcode has actually executed and its true cost is
+            // known
+            gas_cost: 0,
+            depth: self.call_stack.len() as u64,
+            stack,
+            memory,
+            // patched in `step_end`, once the opcode has actually run
+            storage: BTreeMap::new(),
+            delegate_to,
+        });
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter<EthInterpreter>, context: &mut CTX) {
+        let gas_after = interp.control.gas().remaining();
+        if let Some(log) = self.struct_logs.last_mut() {
+            log.gas_cost = log.gas.saturating_sub(gas_after);
+        }
+
+        if let Some((address, key)) = self.pending_storage_key.take() {
+            // The opcode itself already warmed (and, for `SSTORE`, wrote) this slot, so this
+            // lookup is a free re-read of state the journal already has and reflects the value
+            // actually left behind by the step.
+            if let Ok(value) = context.journal_mut().sload(address, key) {
+                if let Some(log) = self.struct_logs.last_mut() {
+                    log.storage.insert(B256::from(key.to_be_bytes()), B256::from(value.data.to_be_bytes()));
+                }
+            }
+        }
+    }
+
+    fn call(&mut self, context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        let kind = match inputs.scheme {
+            CallScheme::Call => "CALL",
+            CallScheme::CallCode => "CALLCODE",
+            CallScheme::DelegateCall => "DELEGATECALL",
+            CallScheme::StaticCall => "STATICCALL",
+        };
+
+        let target = inputs.target_address;
+        let delegation_target = context
+            .journal_mut()
+            .load_account(target)
+            .ok()
+            .and_then(|account| account.info.code.as_ref().map(|code| code.original_byte_slice().to_vec()))
+            .and_then(|code| parse_delegation(&code));
+        let reauthorized_this_block = self.authorized_this_block.contains(&target);
+
+        self.push_frame(
+            kind,
+            inputs.caller,
+            Some(target),
+            inputs.input.clone(),
+            Some(inputs.value.get()),
+            delegation_target,
+            reauthorized_this_block,
+        );
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, outcome: &mut CallOutcome) {
+        let revert_reason = (!outcome.result.is_ok()).then(|| {
+            decode_revert_reason(&outcome.result.output)
+                .unwrap_or_else(|| format!("{:?}", outcome.result.result))
+        });
+        self.pop_frame(Some(outcome.result.output.clone()), revert_reason);
+    }
+
+    fn create(&mut self, _context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.push_frame("CREATE", inputs.caller, None, inputs.init_code.clone(), Some(inputs.value), None, false);
+        None
+    }
+
+    fn create_end(&mut self, _context: &mut CTX, _inputs: &CreateInputs, outcome: &mut CreateOutcome) {
+        let revert_reason = (!outcome.result.is_ok()).then(|| {
+            decode_revert_reason(&outcome.result.output)
+                .unwrap_or_else(|| format!("{:?}", outcome.result.result))
+        });
+        if let Some(frame) = self.call_stack.last_mut() {
+            frame.to = outcome.address;
+        }
+        self.pop_frame(Some(outcome.result.output.clone()), revert_reason);
+    }
+}
@@ -0,0 +1,18 @@
+//! Pool error types.
+
+use alloy_primitives::Address;
+
+/// Errors returned when attempting to insert a transaction into the pool.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PoolError {
+    /// A transaction from the same sender with the same nonce is already in the pool, and the
+    /// incoming transaction didn't beat it by the configured [`PriceBumpPolicy`](crate::pool::replacement::PriceBumpPolicy)
+    /// bump on both effective gas price and priority tip.
+    #[error("replacement transaction underpriced for sender {sender} nonce {nonce}")]
+    ReplacementUnderpriced {
+        /// The sender whose existing transaction would have been replaced.
+        sender: Address,
+        /// The nonce of the existing transaction.
+        nonce: u64,
+    },
+}
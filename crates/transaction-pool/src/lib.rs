@@ -0,0 +1,14 @@
+//! A transaction pool implementation with a configurable replacement and admission policy.
+//!
+//! This is a standalone reference implementation of the policy itself ([`TxPool`], built via
+//! [`PoolBuilder`]); it is not yet wired into the pool the node actually runs
+//! (`reth_transaction_pool::Pool`, the one behind `node.pool` in the example binaries). Swapping
+//! that pool's replacement/admission logic for this one is follow-up work.
+
+pub mod error;
+pub mod pool;
+pub mod traits;
+
+pub use error::PoolError;
+pub use pool::{PoolBuilder, SubPool, TxPool};
+pub use traits::{PoolTransaction, TransactionPool};
@@ -0,0 +1,57 @@
+//! Pool internals: sub-pool bookkeeping, admission/replacement policy, and the builder that wires
+//! them together.
+
+pub mod replacement;
+mod txpool;
+
+pub use replacement::{MinimalEffectiveGasPrice, PriceBumpPolicy, DEFAULT_PRICE_BUMP_PERCENT};
+pub use txpool::{SubPool, TxPool};
+
+use crate::traits::PoolTransaction;
+
+/// Builds a [`TxPool`], configuring its replacement and admission policy.
+///
+/// ```
+/// # use reth_transaction_pool::pool::{PoolBuilder, PriceBumpPolicy};
+/// # fn build<T: reth_transaction_pool::traits::PoolTransaction>() -> reth_transaction_pool::pool::TxPool<T> {
+/// PoolBuilder::default()
+///     .with_replacement_policy(PriceBumpPolicy::new(12))
+///     .with_minimal_effective_gas_price(1_000_000_000)
+///     .build()
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PoolBuilder {
+    replacement_policy: PriceBumpPolicy,
+    minimal_effective_gas_price: MinimalEffectiveGasPrice,
+}
+
+impl Default for PoolBuilder {
+    fn default() -> Self {
+        Self {
+            replacement_policy: PriceBumpPolicy::default(),
+            minimal_effective_gas_price: MinimalEffectiveGasPrice::default(),
+        }
+    }
+}
+
+impl PoolBuilder {
+    /// Overrides the percentage bump a same-sender, same-nonce replacement must clear on both
+    /// effective gas price and priority tip. Defaults to [`DEFAULT_PRICE_BUMP_PERCENT`].
+    pub fn with_replacement_policy(mut self, policy: PriceBumpPolicy) -> Self {
+        self.replacement_policy = policy;
+        self
+    }
+
+    /// Sets the effective gas price floor, in wei, required for a transaction to be promoted to
+    /// the pending sub-pool. Defaults to `0` (no floor).
+    pub fn with_minimal_effective_gas_price(mut self, floor: u128) -> Self {
+        self.minimal_effective_gas_price = MinimalEffectiveGasPrice::new(floor);
+        self
+    }
+
+    /// Builds the configured [`TxPool`].
+    pub fn build<T: PoolTransaction>(self) -> TxPool<T> {
+        TxPool::new(self.replacement_policy, self.minimal_effective_gas_price)
+    }
+}
@@ -0,0 +1,99 @@
+//! Replacement and admission policy for same-sender, same-nonce transactions.
+//!
+//! By default the pool would happily accept any replacement or any transaction regardless of how
+//! little it pays, which makes it trivial to spam. This module adds two independently
+//! configurable knobs, wired up through [`PoolBuilder`](crate::PoolBuilder):
+//!
+//! - [`PriceBumpPolicy`]: when a new transaction collides with an existing same-sender,
+//!   same-nonce transaction, require it to beat the incumbent's *effective* gas price and
+//!   priority tip by a configurable percentage before evicting it.
+//! - [`MinimalEffectiveGasPrice`]: a floor below which a transaction is never promoted out of the
+//!   queued sub-pool, regardless of whether it replaces anything.
+//!
+//! Both operate on the *effective* gas price given the current block's base fee: `gas_price` for
+//! legacy transactions, and `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)` for EIP-1559
+//! transactions.
+
+use crate::traits::PoolTransaction;
+
+/// Default percentage a replacement transaction's effective gas price and priority tip must beat
+/// the incumbent by, matching the common "10% price bump" convention used by most clients.
+pub const DEFAULT_PRICE_BUMP_PERCENT: u32 = 10;
+
+/// The effective gas price and priority tip of a transaction against a given base fee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EffectivePrice {
+    /// `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)` for EIP-1559 transactions, or
+    /// `gas_price` for legacy transactions.
+    pub gas_price: u128,
+    /// `min(max_priority_fee_per_gas, max_fee_per_gas - base_fee)` for EIP-1559 transactions, or
+    /// `gas_price - base_fee` for legacy transactions.
+    pub priority_tip: u128,
+}
+
+impl EffectivePrice {
+    /// Computes the effective price and priority tip of `transaction` against `base_fee`.
+    pub fn for_transaction<T: PoolTransaction>(transaction: &T, base_fee: u64) -> Self {
+        let base_fee = base_fee as u128;
+        let gas_price = transaction.effective_gas_price(Some(base_fee));
+        let priority_tip = gas_price.saturating_sub(base_fee);
+        Self { gas_price, priority_tip }
+    }
+
+    /// Returns `true` if `self` beats `incumbent` by at least `bump_percent` on both the
+    /// effective gas price and the priority tip.
+    fn beats_by(&self, incumbent: &Self, bump_percent: u32) -> bool {
+        let bump = |value: u128| value.saturating_mul(100 + bump_percent as u128) / 100;
+        self.gas_price >= bump(incumbent.gas_price) && self.priority_tip >= bump(incumbent.priority_tip)
+    }
+}
+
+/// Replacement policy requiring a configurable percentage price bump on both the effective gas
+/// price and the priority tip before a same-sender, same-nonce transaction may be evicted.
+#[derive(Debug, Clone, Copy)]
+pub struct PriceBumpPolicy {
+    /// Minimum percentage the replacement must beat the incumbent by, on both the effective gas
+    /// price and the priority tip.
+    pub bump_percent: u32,
+}
+
+impl Default for PriceBumpPolicy {
+    fn default() -> Self {
+        Self { bump_percent: DEFAULT_PRICE_BUMP_PERCENT }
+    }
+}
+
+impl PriceBumpPolicy {
+    /// Creates a new policy requiring the given percentage bump.
+    pub const fn new(bump_percent: u32) -> Self {
+        Self { bump_percent }
+    }
+
+    /// Returns `true` if `candidate` is allowed to replace `incumbent` given the current block's
+    /// `base_fee`.
+    pub fn should_replace<T: PoolTransaction>(&self, incumbent: &T, candidate: &T, base_fee: u64) -> bool {
+        let incumbent_price = EffectivePrice::for_transaction(incumbent, base_fee);
+        let candidate_price = EffectivePrice::for_transaction(candidate, base_fee);
+        candidate_price.beats_by(&incumbent_price, self.bump_percent)
+    }
+}
+
+/// A floor on the effective gas price a transaction must clear to be promoted to the pending
+/// sub-pool, independent of any replacement it may or may not be performing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinimalEffectiveGasPrice {
+    /// The minimum effective gas price, in wei, required for promotion to pending.
+    pub floor: u128,
+}
+
+impl MinimalEffectiveGasPrice {
+    /// Creates a new threshold with the given floor, in wei.
+    pub const fn new(floor: u128) -> Self {
+        Self { floor }
+    }
+
+    /// Returns `true` if `transaction`'s effective gas price against `base_fee` clears the floor.
+    pub fn is_satisfied_by<T: PoolTransaction>(&self, transaction: &T, base_fee: u64) -> bool {
+        EffectivePrice::for_transaction(transaction, base_fee).gas_price >= self.floor
+    }
+}
@@ -0,0 +1,232 @@
+//! The pool's internal transaction containers and admission/replacement logic.
+
+use super::replacement::{MinimalEffectiveGasPrice, PriceBumpPolicy};
+use crate::{error::PoolError, traits::PoolTransaction};
+use alloy_primitives::Address;
+use std::collections::BTreeMap;
+
+/// The sub-pool a transaction currently lives in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubPool {
+    /// Immediately includable: contiguous with the sender's on-chain nonce and above the
+    /// configured [`MinimalEffectiveGasPrice`] floor.
+    Pending,
+    /// Valid but either non-contiguous with the sender's nonce or below the effective gas price
+    /// floor, so not yet (or no longer) includable.
+    Queued,
+}
+
+/// A transaction pool that enforces a [`PriceBumpPolicy`] on same-sender, same-nonce
+/// replacements and a [`MinimalEffectiveGasPrice`] floor on promotion to the pending sub-pool.
+///
+/// Constructed via [`super::PoolBuilder`].
+#[derive(Debug)]
+pub struct TxPool<T: PoolTransaction> {
+    /// All transactions currently in the pool, keyed by `(sender, nonce)`.
+    by_sender_and_nonce: BTreeMap<(Address, u64), (T, SubPool)>,
+    replacement_policy: PriceBumpPolicy,
+    minimal_effective_gas_price: MinimalEffectiveGasPrice,
+}
+
+impl<T: PoolTransaction> TxPool<T> {
+    pub(crate) fn new(replacement_policy: PriceBumpPolicy, minimal_effective_gas_price: MinimalEffectiveGasPrice) -> Self {
+        Self { by_sender_and_nonce: BTreeMap::new(), replacement_policy, minimal_effective_gas_price }
+    }
+
+    /// Attempts to insert `transaction` into the pool against the sender's current
+    /// `on_chain_nonce` and the given block `base_fee`.
+    ///
+    /// If a transaction with the same sender and nonce already exists, `transaction` must beat it
+    /// by the configured [`PriceBumpPolicy`] or this returns
+    /// [`PoolError::ReplacementUnderpriced`]. The transaction is then assigned to the pending
+    /// sub-pool only if it is contiguous with `on_chain_nonce` (i.e. every nonce in between is
+    /// already in the pool) and its effective gas price clears the configured
+    /// [`MinimalEffectiveGasPrice`]; otherwise it is accepted into the queued sub-pool (unless it
+    /// was rejected outright above). Inserting it may also promote previously queued, contiguous
+    /// successors of the same sender that were only blocked on this gap.
+    pub fn add_transaction(
+        &mut self,
+        transaction: T,
+        on_chain_nonce: u64,
+        base_fee: u64,
+    ) -> Result<SubPool, PoolError> {
+        let sender = transaction.sender();
+        let nonce = transaction.nonce();
+
+        if let Some((incumbent, _)) = self.by_sender_and_nonce.get(&(sender, nonce)) {
+            if !self.replacement_policy.should_replace(incumbent, &transaction, base_fee) {
+                return Err(PoolError::ReplacementUnderpriced { sender, nonce });
+            }
+        }
+
+        let contiguous = (on_chain_nonce..nonce).all(|n| self.by_sender_and_nonce.contains_key(&(sender, n)));
+        let sub_pool = if contiguous && self.minimal_effective_gas_price.is_satisfied_by(&transaction, base_fee) {
+            SubPool::Pending
+        } else {
+            SubPool::Queued
+        };
+
+        self.by_sender_and_nonce.insert((sender, nonce), (transaction, sub_pool));
+
+        if sub_pool == SubPool::Pending {
+            self.promote_contiguous_successors(sender, nonce, base_fee);
+        }
+
+        Ok(sub_pool)
+    }
+
+    /// Returns the sub-pool `sender`/`nonce` currently lives in, if present.
+    pub fn sub_pool_of(&self, sender: Address, nonce: u64) -> Option<SubPool> {
+        self.by_sender_and_nonce.get(&(sender, nonce)).map(|(_, sub_pool)| *sub_pool)
+    }
+
+    /// Promotes `sender`'s queued transactions immediately following `nonce` to pending, for as
+    /// long as they remain contiguous and clear the minimal effective gas price floor.
+    ///
+    /// Called after a transaction is promoted to pending, since that may have closed a nonce gap
+    /// that was blocking its successors.
+    fn promote_contiguous_successors(&mut self, sender: Address, nonce: u64, base_fee: u64) {
+        let minimal_effective_gas_price = self.minimal_effective_gas_price;
+        let mut next_nonce = nonce + 1;
+        while let Some((transaction, sub_pool)) = self.by_sender_and_nonce.get_mut(&(sender, next_nonce)) {
+            if *sub_pool == SubPool::Pending || !minimal_effective_gas_price.is_satisfied_by(transaction, base_fee) {
+                break;
+            }
+            *sub_pool = SubPool::Pending;
+            next_nonce += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::PoolBuilder;
+    use alloy_primitives::address;
+
+    #[derive(Debug, Clone)]
+    struct MockTransaction {
+        sender: Address,
+        nonce: u64,
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    }
+
+    impl PoolTransaction for MockTransaction {
+        fn sender(&self) -> Address {
+            self.sender
+        }
+
+        fn nonce(&self) -> u64 {
+            self.nonce
+        }
+
+        fn effective_gas_price(&self, base_fee: Option<u128>) -> u128 {
+            let base_fee = base_fee.unwrap_or_default();
+            self.max_fee_per_gas.min(base_fee + self.max_priority_fee_per_gas)
+        }
+    }
+
+    const SENDER: Address = address!("0x0000000000000000000000000000000000000001");
+
+    #[test]
+    fn rejects_replacement_below_price_bump() {
+        let mut pool: TxPool<MockTransaction> = PoolBuilder::default().build();
+        let base_fee = 10;
+
+        pool.add_transaction(
+            MockTransaction { sender: SENDER, nonce: 0, max_fee_per_gas: 100, max_priority_fee_per_gas: 10 },
+            0,
+            base_fee,
+        )
+        .unwrap();
+
+        let err = pool
+            .add_transaction(
+                MockTransaction { sender: SENDER, nonce: 0, max_fee_per_gas: 105, max_priority_fee_per_gas: 10 },
+                0,
+                base_fee,
+            )
+            .unwrap_err();
+        assert_eq!(err, PoolError::ReplacementUnderpriced { sender: SENDER, nonce: 0 });
+    }
+
+    #[test]
+    fn accepts_replacement_above_price_bump() {
+        let mut pool: TxPool<MockTransaction> = PoolBuilder::default().build();
+        let base_fee = 10;
+
+        pool.add_transaction(
+            MockTransaction { sender: SENDER, nonce: 0, max_fee_per_gas: 100, max_priority_fee_per_gas: 10 },
+            0,
+            base_fee,
+        )
+        .unwrap();
+
+        pool.add_transaction(
+            MockTransaction { sender: SENDER, nonce: 0, max_fee_per_gas: 200, max_priority_fee_per_gas: 50 },
+            0,
+            base_fee,
+        )
+        .unwrap();
+
+        assert_eq!(pool.sub_pool_of(SENDER, 0), Some(SubPool::Pending));
+    }
+
+    #[test]
+    fn below_minimal_effective_gas_price_stays_queued() {
+        let mut pool: TxPool<MockTransaction> =
+            PoolBuilder::default().with_minimal_effective_gas_price(50).build();
+
+        let sub_pool = pool
+            .add_transaction(
+                MockTransaction { sender: SENDER, nonce: 0, max_fee_per_gas: 20, max_priority_fee_per_gas: 1 },
+                0,
+                10,
+            )
+            .unwrap();
+
+        assert_eq!(sub_pool, SubPool::Queued);
+    }
+
+    #[test]
+    fn non_contiguous_nonce_stays_queued() {
+        let mut pool: TxPool<MockTransaction> = PoolBuilder::default().build();
+
+        // on-chain nonce is 0, but this transaction is nonce 1: there's a gap at nonce 0.
+        let sub_pool = pool
+            .add_transaction(
+                MockTransaction { sender: SENDER, nonce: 1, max_fee_per_gas: 100, max_priority_fee_per_gas: 10 },
+                0,
+                10,
+            )
+            .unwrap();
+
+        assert_eq!(sub_pool, SubPool::Queued);
+    }
+
+    #[test]
+    fn filling_a_nonce_gap_promotes_its_queued_successor() {
+        let mut pool: TxPool<MockTransaction> = PoolBuilder::default().build();
+        let base_fee = 10;
+
+        let nonce_1 = pool
+            .add_transaction(
+                MockTransaction { sender: SENDER, nonce: 1, max_fee_per_gas: 100, max_priority_fee_per_gas: 10 },
+                0,
+                base_fee,
+            )
+            .unwrap();
+        assert_eq!(nonce_1, SubPool::Queued);
+
+        let nonce_0 = pool
+            .add_transaction(
+                MockTransaction { sender: SENDER, nonce: 0, max_fee_per_gas: 100, max_priority_fee_per_gas: 10 },
+                0,
+                base_fee,
+            )
+            .unwrap();
+        assert_eq!(nonce_0, SubPool::Pending);
+        assert_eq!(pool.sub_pool_of(SENDER, 1), Some(SubPool::Pending));
+    }
+}
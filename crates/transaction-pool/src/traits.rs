@@ -0,0 +1,29 @@
+//! Core traits implemented by anything that can sit in the transaction pool.
+
+use alloy_primitives::Address;
+
+/// A transaction as seen by the pool: enough surface to order, validate and price it without the
+/// pool needing to know about any particular transaction encoding.
+pub trait PoolTransaction: Clone + Send + Sync + 'static {
+    /// The sender recovered from the transaction's signature.
+    fn sender(&self) -> Address;
+
+    /// The transaction's nonce.
+    fn nonce(&self) -> u64;
+
+    /// The transaction's effective gas price given the current block's `base_fee`.
+    ///
+    /// For EIP-1559 transactions this is `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)`;
+    /// for legacy transactions it is simply `gas_price`. `base_fee` is `None` only when no block
+    /// is available to price against (e.g. pre-London chains).
+    fn effective_gas_price(&self, base_fee: Option<u128>) -> u128;
+}
+
+/// Pool-facing trait naming the pool's transaction type.
+///
+/// It declares no methods: transactions are submitted and their sub-pool queried through
+/// [`TxPool`](crate::TxPool) directly.
+pub trait TransactionPool: Send + Sync {
+    /// The pool's transaction type.
+    type Transaction: PoolTransaction;
+}
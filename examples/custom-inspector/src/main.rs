@@ -7,30 +7,34 @@
 //! ```
 //!
 //! If no recipients are specified, all transactions will be inspected.
+//!
+//! To reproduce a multi-tx scenario (e.g. a front-running bundle, or a setup-then-exploit
+//! sequence), stage the earlier transactions as raw RLP and they'll be replayed, with their
+//! state committed to a copy-on-write overlay, before each pending transaction is inspected:
+//!
+//! ```sh
+//! cargo run --release -p custom-inspector -- node --raw-txs 0x02f8..,0x02f8..
+//! ```
 
 #![warn(unused_crate_dependencies)]
 
+mod replay;
+
 use alloy_eips::BlockNumberOrTag;
 use alloy_evm::Evm;
-use alloy_primitives::Address;
+use alloy_primitives::{Address, Bytes};
 use alloy_rpc_types_eth::{state::EvmOverrides, TransactionRequest};
 use clap::Parser;
 use futures_util::StreamExt;
 use reth_ethereum::{
     cli::{chainspec::EthereumChainSpecParser, interface::Cli},
-    evm::{
-        primitives::ConfigureEvm,
-        revm::revm::{
-            bytecode::opcode::OpCode,
-            context_interface::ContextTr,
-            inspector::Inspector,
-            interpreter::{interpreter::EthInterpreter, interpreter_types::Jumps, Interpreter},
-        },
-    },
+    evm::{primitives::ConfigureEvm, revm::revm::DatabaseCommit},
     node::{builder::NodeHandle, EthereumNode},
     pool::TransactionPool,
     rpc::api::eth::helpers::Call,
 };
+use reth_evm::tracing::StructLogInspector;
+use replay::{replay_raw_transactions, RawTransaction};
 
 fn main() {
     Cli::<EthereumChainSpecParser, RethCliTxpoolExt>::parse()
@@ -57,39 +61,61 @@ fn main() {
                     if let Some(recipient) = tx.to() {
                         if args.is_match(&recipient) {
                             // convert the pool transaction
+                            let consensus_tx = tx.to_consensus();
                             let call_request =
-                                TransactionRequest::from_recovered_transaction(tx.to_consensus());
+                                TransactionRequest::from_recovered_transaction(consensus_tx.clone());
+
+                            // if the transaction being inspected is itself an EIP-7702 tx, its
+                            // authorization list lets us annotate delegated call frames with the
+                            // authority that set them up
+                            let authorization_list = consensus_tx
+                                .as_eip7702()
+                                .map(|tx| tx.authorization_list.clone())
+                                .unwrap_or_default();
 
                             let evm_config = node.evm_config.clone();
+                            let staged = args.staged_transactions();
+                            let commit_inspected_tx = args.commit_inspected_tx;
 
                             let result = eth_api
                                 .spawn_with_call_at(
                                     call_request,
                                     BlockNumberOrTag::Latest.into(),
                                     EvmOverrides::default(),
-                                    move |db, evm_env, tx_env| {
-                                        let mut dummy_inspector = DummyInspector::default();
+                                    move |mut db, evm_env, tx_env| {
+                                        // replay any staged setup transactions first, committing
+                                        // their state so the target inspection observes them
+                                        replay_raw_transactions(
+                                            &evm_config,
+                                            &mut db,
+                                            evm_env.clone(),
+                                            &staged,
+                                        )?;
+
+                                        let mut struct_log_inspector =
+                                            StructLogInspector::new().with_authorization_list(&authorization_list);
                                         let mut evm = evm_config.evm_with_env_and_inspector(
                                             db,
                                             evm_env,
-                                            &mut dummy_inspector,
+                                            &mut struct_log_inspector,
                                         );
                                         // execute the transaction on a blocking task and await
                                         // the
                                         // inspector result
-                                        let _ = evm.transact(tx_env)?;
-                                        Ok(dummy_inspector)
+                                        let result = evm.transact(tx_env)?;
+                                        if commit_inspected_tx {
+                                            evm.db_mut().commit(result.state);
+                                        }
+                                        Ok(struct_log_inspector)
                                     },
                                 )
                                 .await;
 
-                            if let Ok(ret_val) = result {
+                            if let Ok(inspector) = result {
                                 let hash = tx.hash();
-                                println!(
-                                    "Inspector result for transaction {}: \n {}",
-                                    hash,
-                                    ret_val.ret_val.join("\n")
-                                );
+                                let struct_logs = serde_json::to_string_pretty(&inspector.struct_logs)
+                                    .unwrap_or_default();
+                                println!("Inspector result for transaction {hash}: \n {struct_logs}");
                             }
                         }
                     }
@@ -101,12 +127,25 @@ fn main() {
         .unwrap();
 }
 
-/// Our custom cli args extension that adds one flag to reth default CLI.
+/// Our custom cli args extension that adds flags to reth default CLI.
 #[derive(Debug, Clone, Default, clap::Args)]
 struct RethCliTxpoolExt {
     /// The addresses of the recipients that we want to trace.
     #[arg(long, value_delimiter = ',')]
     pub recipients: Vec<Address>,
+
+    /// Raw, RLP-encoded signed transactions to replay (in order) against a copy-on-write
+    /// overlay of the forked state before inspecting each pending transaction, so their effects
+    /// are visible to it.
+    #[arg(long = "raw-txs", value_delimiter = ',')]
+    pub raw_txs: Vec<Bytes>,
+
+    /// Whether the inspected pending transaction's own state changes are also committed to the
+    /// overlay after inspection. The overlay is built afresh from the latest block for each
+    /// pending transaction and dropped once it has been inspected, so these changes are not seen
+    /// by later inspections.
+    #[arg(long)]
+    pub commit_inspected_tx: bool,
 }
 
 impl RethCliTxpoolExt {
@@ -114,25 +153,9 @@ impl RethCliTxpoolExt {
     pub fn is_match(&self, recipient: &Address) -> bool {
         self.recipients.is_empty() || self.recipients.contains(recipient)
     }
-}
-
-/// A dummy inspector that logs the opcodes and their corresponding program counter for a
-/// transaction
-#[derive(Default, Debug, Clone)]
-struct DummyInspector {
-    ret_val: Vec<String>,
-}
 
-impl<CTX> Inspector<CTX, EthInterpreter> for DummyInspector
-where
-    CTX: ContextTr,
-{
-    /// This method is called at each step of the EVM execution.
-    /// It checks if the current opcode is valid and if so, it stores the opcode and its
-    /// corresponding program counter in the `ret_val` vector.
-    fn step(&mut self, interp: &mut Interpreter<EthInterpreter>, _context: &mut CTX) {
-        if let Some(opcode) = OpCode::new(interp.bytecode.opcode()) {
-            self.ret_val.push(format!("{}: {}", interp.bytecode.pc(), opcode));
-        }
+    /// Returns the configured raw transactions as [`RawTransaction`]s to stage before inspection.
+    pub fn staged_transactions(&self) -> Vec<RawTransaction> {
+        self.raw_txs.iter().cloned().map(RawTransaction).collect()
     }
 }
@@ -0,0 +1,65 @@
+//! Support for replaying an ordered list of raw, RLP-encoded signed transactions against a
+//! forked state before running the target inspection.
+//!
+//! This mirrors a "send raw transaction then keep its effects" workflow: each staged transaction
+//! is executed with `commit`, so its state mutations are visible to the next one, and only once
+//! the whole list has landed does the target transaction get inspected on top of the resulting
+//! state.
+
+use alloy_consensus::{
+    transaction::{Recovered, SignerRecoverable},
+    TxEnvelope,
+};
+use alloy_eips::eip2718::Decodable2718;
+use alloy_primitives::Bytes;
+use reth_ethereum::evm::{
+    primitives::{ConfigureEvm, Database},
+    revm::revm::{inspector::NoOpInspector, DatabaseCommit, Evm},
+};
+
+/// A single transaction to stage before the target inspection runs, given as its raw RLP
+/// encoding straight off the wire (e.g. from `eth_sendRawTransaction`).
+#[derive(Debug, Clone)]
+pub struct RawTransaction(pub Bytes);
+
+impl RawTransaction {
+    /// Decodes the RLP payload into a typed envelope and recovers its sender, so the staged
+    /// transaction executes from (and debits/credits nonce and balance for) the right account
+    /// rather than a zero or unset caller.
+    pub fn decode(&self) -> eyre::Result<Recovered<TxEnvelope>> {
+        let tx = TxEnvelope::decode_2718(&mut self.0.as_ref())?;
+        let signer = tx.recover_signer()?;
+        Ok(Recovered::new_unchecked(tx, signer))
+    }
+}
+
+/// Sequentially executes `staged` against `db`, committing each transaction's state changes so
+/// the next staged transaction (and the subsequent target inspection) observes them.
+///
+/// `db` is expected to be a journaled, copy-on-write overlay seeded from the historical block
+/// (e.g. a `CacheDB` wrapping a `StateProviderDatabase`), so none of these mutations touch the
+/// canonical state.
+pub fn replay_raw_transactions<EvmConfig, DB>(
+    evm_config: &EvmConfig,
+    db: &mut DB,
+    evm_env: EvmConfig::EvmEnv,
+    staged: &[RawTransaction],
+) -> eyre::Result<()>
+where
+    EvmConfig: ConfigureEvm,
+    DB: Database + DatabaseCommit,
+{
+    for raw in staged {
+        let tx = raw.decode()?;
+        let tx_env = evm_config.tx_env(&tx);
+
+        // Run with a no-op inspector: we only care about the resulting state, not the trace, for
+        // the setup transactions.
+        let mut evm = evm_config.evm_with_env_and_inspector(&mut *db, evm_env.clone(), NoOpInspector);
+        let result = evm.transact(tx_env)?;
+        drop(evm);
+        db.commit(result.state);
+    }
+
+    Ok(())
+}